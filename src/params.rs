@@ -1,11 +1,46 @@
 // Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
 use crate::display::{display_optional, SliceDisplayer};
+use crate::ts_expr::{expr_to_expr_def, span_to_repr, ExprDef};
 use crate::ts_type::{ts_type_ann_to_def, TsTypeDef};
-use deno_ast::swc::ast::{ObjectPatProp, Pat, TsFnParam};
+use deno_ast::swc::ast::{
+  ObjectPatProp, Pat, ParamOrTsParamProp, TsFnParam, TsParamProp,
+  TsParamPropParam,
+};
+use deno_ast::swc::common::Spanned;
 use deno_ast::ParsedSource;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum Accessibility {
+  Public,
+  Protected,
+  Private,
+}
+
+impl Display for Accessibility {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      Accessibility::Public => write!(f, "public"),
+      Accessibility::Protected => write!(f, "protected"),
+      Accessibility::Private => write!(f, "private"),
+    }
+  }
+}
+
+impl From<deno_ast::swc::ast::Accessibility> for Accessibility {
+  fn from(accessibility: deno_ast::swc::ast::Accessibility) -> Self {
+    match accessibility {
+      deno_ast::swc::ast::Accessibility::Public => Accessibility::Public,
+      deno_ast::swc::ast::Accessibility::Protected => {
+        Accessibility::Protected
+      }
+      deno_ast::swc::ast::Accessibility::Private => Accessibility::Private,
+    }
+  }
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "kind")]
@@ -19,7 +54,7 @@ pub enum ParamDef {
   #[serde(rename_all = "camelCase")]
   Assign {
     left: Box<ParamDef>,
-    right: String,
+    right: Box<ExprDef>,
     ts_type: Option<TsTypeDef>,
   },
   #[serde(rename_all = "camelCase")]
@@ -39,6 +74,23 @@ pub enum ParamDef {
     arg: Box<ParamDef>,
     ts_type: Option<TsTypeDef>,
   },
+  #[serde(rename_all = "camelCase")]
+  Property {
+    param: Box<ParamDef>,
+    decorators: Vec<String>,
+    accessibility: Option<Accessibility>,
+    is_override: bool,
+    is_readonly: bool,
+  },
+  /// Fallback for patterns we don't model structurally (e.g. `Pat::Expr` or
+  /// `Pat::Invalid`), so a malformed or exotic AST still produces a doc node
+  /// instead of panicking. `repr` is the recovered source text when a
+  /// `ParsedSource` is available.
+  #[serde(rename_all = "camelCase")]
+  Unsupported {
+    repr: String,
+    ts_type: Option<TsTypeDef>,
+  },
 }
 
 impl Display for ParamDef {
@@ -68,13 +120,16 @@ impl Display for ParamDef {
         }
         Ok(())
       }
-      ParamDef::Assign { left, ts_type, .. } => {
+      ParamDef::Assign {
+        left,
+        right,
+        ts_type,
+      } => {
         write!(f, "{}", left)?;
         if let Some(ts_type) = ts_type {
           write!(f, ": {}", ts_type)?;
         }
-        // TODO(SyrupThinker) As we cannot display expressions the value is just omitted
-        // write!(f, " = {}", right)?;
+        write!(f, " = {}", right)?;
         Ok(())
       }
       ParamDef::Identifier {
@@ -111,6 +166,34 @@ impl Display for ParamDef {
         }
         Ok(())
       }
+      ParamDef::Property {
+        param,
+        decorators,
+        accessibility,
+        is_override,
+        is_readonly,
+      } => {
+        for decorator in decorators {
+          write!(f, "{} ", decorator)?;
+        }
+        if let Some(accessibility) = accessibility {
+          write!(f, "{} ", accessibility)?;
+        }
+        if *is_override {
+          write!(f, "override ")?;
+        }
+        if *is_readonly {
+          write!(f, "readonly ")?;
+        }
+        write!(f, "{}", param)
+      }
+      ParamDef::Unsupported { repr, ts_type } => {
+        write!(f, "{}", repr)?;
+        if let Some(ts_type) = ts_type {
+          write!(f, ": {}", ts_type)?;
+        }
+        Ok(())
+      }
     }
   }
 }
@@ -119,26 +202,42 @@ impl Display for ParamDef {
 #[serde(rename_all = "camelCase")]
 #[serde(tag = "kind")]
 pub enum ObjectPatPropDef {
-  Assign { key: String, value: Option<String> },
+  Assign { key: String, value: Option<ExprDef> },
   KeyValue { key: String, value: Box<ParamDef> },
   Rest { arg: Box<ParamDef> },
 }
 
+fn is_trivial_identifier(key: &str, value: &ParamDef) -> bool {
+  matches!(
+    value,
+    ParamDef::Identifier {
+      name,
+      optional: false,
+      ts_type: None,
+    } if name == key
+  )
+}
+
 impl Display for ObjectPatPropDef {
   fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
     match self {
-      ObjectPatPropDef::KeyValue { key, .. } => {
-        // The internal identifier does not need to be exposed
-        write!(f, "{}", key)
-      }
-      ObjectPatPropDef::Assign { key, value } => {
-        if let Some(_value) = value {
-          // TODO(SyrupThinker) As we cannot display expressions the value is just omitted
+      // Renamed (`{ a: localA }`) and nested (`{ a: { b } }`) destructuring
+      // render as `key: value`; the common non-renamed case, where `value`
+      // is a trivial identifier matching `key`, stays compact as just `key`.
+      ObjectPatPropDef::KeyValue { key, value } => {
+        if is_trivial_identifier(key, value) {
           write!(f, "{}", key)
         } else {
-          write!(f, "{}", key)
+          write!(f, "{}: {}", key, value)
         }
       }
+      ObjectPatPropDef::Assign { key, value } => {
+        write!(f, "{}", key)?;
+        if let Some(value) = value {
+          write!(f, " = {}", value)?;
+        }
+        Ok(())
+      }
       ObjectPatPropDef::Rest { arg } => write!(f, "...{}", arg),
     }
   }
@@ -150,6 +249,10 @@ pub fn ident_to_param_def(
 ) -> ParamDef {
   let ts_type = ident.type_ann.as_ref().map(|rt| ts_type_ann_to_def(rt));
 
+  // A leading `this: Foo` parameter (TypeScript's `this` parameter) is
+  // parsed as a regular binding identifier named `this`, so it already
+  // carries its type annotation through this path and stays visible as
+  // `this: Foo` when rendered.
   ParamDef::Identifier {
     name: ident.id.sym.to_string(),
     optional: ident.id.optional,
@@ -176,7 +279,10 @@ fn object_pat_prop_to_def(
   match object_pat_prop {
     ObjectPatProp::Assign(assign) => ObjectPatPropDef::Assign {
       key: assign.key.sym.to_string(),
-      value: assign.value.as_ref().map(|_| "[UNSUPPORTED]".to_string()),
+      value: assign
+        .value
+        .as_ref()
+        .map(|expr| expr_to_expr_def(parsed_source, expr)),
     },
     ObjectPatProp::KeyValue(keyvalue) => ObjectPatPropDef::KeyValue {
       key: prop_name_to_string(parsed_source, &keyvalue.key),
@@ -238,11 +344,14 @@ pub fn assign_pat_to_param_def(
 
   ParamDef::Assign {
     left: Box::new(pat_to_param_def(parsed_source, &*assign_pat.left)),
-    right: "[UNSUPPORTED]".to_string(),
+    right: Box::new(expr_to_expr_def(parsed_source, &*assign_pat.right)),
     ts_type,
   }
 }
 
+/// Converts a `Pat` into a `ParamDef`. Total over every `Pat` the parser can
+/// produce: patterns we don't model structurally fall back to
+/// `ParamDef::Unsupported` instead of panicking.
 pub fn pat_to_param_def(
   parsed_source: Option<&ParsedSource>,
   pat: &deno_ast::swc::ast::Pat,
@@ -257,7 +366,14 @@ pub fn pat_to_param_def(
     Pat::Assign(assign_pat) => {
       assign_pat_to_param_def(parsed_source, assign_pat)
     }
-    _ => unreachable!(),
+    Pat::Expr(expr) => ParamDef::Unsupported {
+      repr: span_to_repr(parsed_source, &expr.span()),
+      ts_type: None,
+    },
+    Pat::Invalid(invalid) => ParamDef::Unsupported {
+      repr: span_to_repr(parsed_source, &invalid.span),
+      ts_type: None,
+    },
   }
 }
 
@@ -277,6 +393,53 @@ pub fn ts_fn_param_to_param_def(
   }
 }
 
+pub fn ts_param_prop_to_param_def(
+  parsed_source: Option<&ParsedSource>,
+  ts_param_prop: &TsParamProp,
+) -> ParamDef {
+  let param = match &ts_param_prop.param {
+    TsParamPropParam::Ident(ident) => ident_to_param_def(parsed_source, ident),
+    TsParamPropParam::Assign(assign_pat) => {
+      assign_pat_to_param_def(parsed_source, assign_pat)
+    }
+  };
+  let decorators = ts_param_prop
+    .decorators
+    .iter()
+    .map(|decorator| span_to_repr(parsed_source, &decorator.span))
+    .collect();
+
+  ParamDef::Property {
+    param: Box::new(param),
+    decorators,
+    accessibility: ts_param_prop.accessibility.map(Accessibility::from),
+    is_override: ts_param_prop.is_override,
+    is_readonly: ts_param_prop.readonly,
+  }
+}
+
+/// Converts a constructor parameter, which may either be a plain `Pat` or a
+/// TypeScript parameter property (`private readonly id: number`).
+///
+/// TODO(SeparateRecords/deno_doc#chunk0-2): this module has no `class.rs`,
+/// so nothing calls this yet. Whichever module walks
+/// `Constructor::params: Vec<ParamOrTsParamProp>` must call this instead of
+/// `pat_to_param_def` there, or constructor parameter properties keep being
+/// dropped in practice despite this conversion existing.
+pub fn param_or_ts_param_prop_to_param_def(
+  parsed_source: Option<&ParsedSource>,
+  param: &ParamOrTsParamProp,
+) -> ParamDef {
+  match param {
+    ParamOrTsParamProp::Param(param) => {
+      pat_to_param_def(parsed_source, &param.pat)
+    }
+    ParamOrTsParamProp::TsParamProp(ts_param_prop) => {
+      ts_param_prop_to_param_def(parsed_source, ts_param_prop)
+    }
+  }
+}
+
 pub fn prop_name_to_string(
   parsed_source: Option<&ParsedSource>,
   prop_name: &deno_ast::swc::ast::PropName,
@@ -287,8 +450,181 @@ pub fn prop_name_to_string(
     PropName::Str(str_) => str_.value.to_string(),
     PropName::Num(num) => num.value.to_string(),
     PropName::BigInt(num) => num.value.to_string(),
-    PropName::Computed(comp_prop_name) => parsed_source
-      .map(|s| s.source().span_text(&comp_prop_name.span).to_string())
-      .unwrap_or_else(|| "<UNAVAILABLE>".to_string()),
+    PropName::Computed(comp_prop_name) => {
+      span_to_repr(parsed_source, &comp_prop_name.span)
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use deno_ast::parse_program;
+  use deno_ast::ParseParams;
+  use deno_ast::SourceTextInfo;
+
+  fn parse(source: &str) -> ParsedSource {
+    parse_program(ParseParams {
+      specifier: "file:///test.ts".to_string(),
+      text_info: SourceTextInfo::from_string(source.to_string()),
+      media_type: deno_ast::MediaType::TypeScript,
+      capture_tokens: true,
+      maybe_syntax: None,
+      scope_analysis: false,
+    })
+    .unwrap()
+  }
+
+  /// The module's sole statement must be a class declaration whose
+  /// constructor takes exactly one parameter.
+  fn first_ctor_param(parsed_source: &ParsedSource) -> ParamOrTsParamProp {
+    use deno_ast::swc::ast::ClassMember;
+    use deno_ast::swc::ast::Decl;
+    use deno_ast::swc::ast::ModuleItem;
+    use deno_ast::swc::ast::Program;
+    use deno_ast::swc::ast::Stmt;
+
+    let module = match parsed_source.program_ref() {
+      Program::Module(module) => module,
+      _ => panic!("expected a module"),
+    };
+    let class_decl = match &module.body[0] {
+      ModuleItem::Stmt(Stmt::Decl(Decl::Class(class_decl))) => class_decl,
+      _ => panic!("expected a class declaration"),
+    };
+    let constructor = class_decl
+      .class
+      .body
+      .iter()
+      .find_map(|member| match member {
+        ClassMember::Constructor(constructor) => Some(constructor),
+        _ => None,
+      })
+      .expect("expected a constructor");
+    constructor.params[0].clone()
+  }
+
+  #[test]
+  fn parameter_property_modifier_order() {
+    let parsed_source =
+      parse("class C { constructor(private readonly id: number) {} }");
+    let ctor_param = first_ctor_param(&parsed_source);
+    let def =
+      param_or_ts_param_prop_to_param_def(Some(&parsed_source), &ctor_param);
+    assert_eq!(def.to_string(), "private readonly id: number");
+    match def {
+      ParamDef::Property {
+        accessibility,
+        is_readonly,
+        is_override,
+        ..
+      } => {
+        assert_eq!(accessibility, Some(Accessibility::Private));
+        assert!(is_readonly);
+        assert!(!is_override);
+      }
+      _ => panic!("expected ParamDef::Property"),
+    }
+  }
+
+  #[test]
+  fn pat_invalid_falls_back_to_unsupported() {
+    use deno_ast::swc::common::DUMMY_SP;
+
+    let pat = Pat::Invalid(deno_ast::swc::ast::Invalid { span: DUMMY_SP });
+    let def = pat_to_param_def(None, &pat);
+    match def {
+      ParamDef::Unsupported { repr, ts_type } => {
+        assert_eq!(repr, "<UNAVAILABLE>");
+        assert!(ts_type.is_none());
+      }
+      _ => panic!("expected ParamDef::Unsupported"),
+    }
+  }
+
+  #[test]
+  fn pat_expr_falls_back_to_unsupported() {
+    use deno_ast::swc::ast::Expr;
+    use deno_ast::swc::ast::Lit;
+    use deno_ast::swc::ast::Null;
+    use deno_ast::swc::common::DUMMY_SP;
+
+    let pat = Pat::Expr(Box::new(Expr::Lit(Lit::Null(Null {
+      span: DUMMY_SP,
+    }))));
+    let def = pat_to_param_def(None, &pat);
+    match def {
+      ParamDef::Unsupported { repr, ts_type } => {
+        assert_eq!(repr, "<UNAVAILABLE>");
+        assert!(ts_type.is_none());
+      }
+      _ => panic!("expected ParamDef::Unsupported"),
+    }
+  }
+
+  #[test]
+  fn object_pat_prop_key_value_renders_compactly_when_not_renamed() {
+    let prop = ObjectPatPropDef::KeyValue {
+      key: "a".to_string(),
+      value: Box::new(ParamDef::Identifier {
+        name: "a".to_string(),
+        optional: false,
+        ts_type: None,
+      }),
+    };
+    assert_eq!(prop.to_string(), "a");
+  }
+
+  #[test]
+  fn object_pat_prop_key_value_renders_renamed_binding() {
+    let prop = ObjectPatPropDef::KeyValue {
+      key: "a".to_string(),
+      value: Box::new(ParamDef::Identifier {
+        name: "localA".to_string(),
+        optional: false,
+        ts_type: None,
+      }),
+    };
+    assert_eq!(prop.to_string(), "a: localA");
+  }
+
+  #[test]
+  fn object_pat_prop_key_value_renders_nested_destructuring() {
+    let prop = ObjectPatPropDef::KeyValue {
+      key: "a".to_string(),
+      value: Box::new(ParamDef::Object {
+        props: vec![ObjectPatPropDef::KeyValue {
+          key: "b".to_string(),
+          value: Box::new(ParamDef::Identifier {
+            name: "b".to_string(),
+            optional: false,
+            ts_type: None,
+          }),
+        }],
+        optional: false,
+        ts_type: None,
+      }),
+    };
+    assert_eq!(prop.to_string(), "a: {b}");
+  }
+
+  #[test]
+  fn renamed_binding_is_reachable_through_param_def_object_display() {
+    // Confirms the renamed-binding rendering above is actually exercised
+    // through the normal ParamDef::Object Display path (via
+    // SliceDisplayer), not just callable in isolation.
+    let param = ParamDef::Object {
+      props: vec![ObjectPatPropDef::KeyValue {
+        key: "a".to_string(),
+        value: Box::new(ParamDef::Identifier {
+          name: "localA".to_string(),
+          optional: false,
+          ts_type: None,
+        }),
+      }],
+      optional: false,
+      ts_type: None,
+    };
+    assert_eq!(param.to_string(), "{a: localA}");
   }
 }
@@ -0,0 +1,330 @@
+// Copyright 2020-2021 the Deno authors. All rights reserved. MIT license.
+use crate::params::prop_name_to_string;
+use deno_ast::swc::ast::Expr;
+use deno_ast::swc::ast::Lit;
+use deno_ast::swc::ast::Prop;
+use deno_ast::swc::ast::PropName;
+use deno_ast::swc::ast::PropOrSpread;
+use deno_ast::swc::common::Span;
+use deno_ast::swc::common::Spanned;
+use deno_ast::ParsedSource;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Recovers the source text for `span` via `parsed_source`, falling back to
+/// `<UNAVAILABLE>` when no `ParsedSource` was supplied.
+pub(crate) fn span_to_repr(
+  parsed_source: Option<&ParsedSource>,
+  span: &Span,
+) -> String {
+  parsed_source
+    .map(|s| s.source().span_text(span).to_string())
+    .unwrap_or_else(|| "<UNAVAILABLE>".to_string())
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "kind")]
+pub enum ExprDef {
+  #[serde(rename_all = "camelCase")]
+  Str { value: String },
+  #[serde(rename_all = "camelCase")]
+  Num { value: f64 },
+  #[serde(rename_all = "camelCase")]
+  Bool { value: bool },
+  Null,
+  #[serde(rename_all = "camelCase")]
+  BigInt { value: String },
+  #[serde(rename_all = "camelCase")]
+  Regex { pattern: String, flags: String },
+  #[serde(rename_all = "camelCase")]
+  Array { elements: Vec<Option<ExprDef>> },
+  #[serde(rename_all = "camelCase")]
+  Object { props: Vec<ObjectPropDef> },
+  #[serde(rename_all = "camelCase")]
+  Ident { name: String },
+  #[serde(rename_all = "camelCase")]
+  Other { repr: String },
+}
+
+/// A single property of an object literal default value. Non-computed
+/// key/value pairs and shorthand properties are modelled structurally;
+/// spreads, computed keys, and accessors/methods fall back to `repr`,
+/// mirroring how `ExprDef::Other` recovers source text elsewhere.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "kind")]
+pub enum ObjectPropDef {
+  #[serde(rename_all = "camelCase")]
+  KeyValue { key: String, value: Box<ExprDef> },
+  #[serde(rename_all = "camelCase")]
+  Shorthand { key: String },
+  #[serde(rename_all = "camelCase")]
+  Other { repr: String },
+}
+
+impl Display for ObjectPropDef {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      ObjectPropDef::KeyValue { key, value } => {
+        write!(f, "{}: {}", key, value)
+      }
+      ObjectPropDef::Shorthand { key } => write!(f, "{}", key),
+      ObjectPropDef::Other { repr } => write!(f, "{}", repr),
+    }
+  }
+}
+
+impl Display for ExprDef {
+  fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+    match self {
+      ExprDef::Str { value } => write!(f, "{:?}", value),
+      ExprDef::Num { value } => write!(f, "{}", value),
+      ExprDef::Bool { value } => write!(f, "{}", value),
+      ExprDef::Null => write!(f, "null"),
+      ExprDef::BigInt { value } => write!(f, "{}n", value),
+      ExprDef::Regex { pattern, flags } => write!(f, "/{}/{}", pattern, flags),
+      ExprDef::Array { elements } => {
+        write!(f, "[")?;
+        if !elements.is_empty() {
+          if let Some(v) = &elements[0] {
+            write!(f, "{}", v)?;
+          }
+          for maybe_v in &elements[1..] {
+            write!(f, ", ")?;
+            if let Some(v) = maybe_v {
+              write!(f, "{}", v)?;
+            }
+          }
+        }
+        write!(f, "]")
+      }
+      ExprDef::Object { props } => {
+        write!(f, "{{")?;
+        if !props.is_empty() {
+          write!(f, "{}", props[0])?;
+          for prop in &props[1..] {
+            write!(f, ", {}", prop)?;
+          }
+        }
+        write!(f, "}}")
+      }
+      ExprDef::Ident { name } => write!(f, "{}", name),
+      ExprDef::Other { repr } => write!(f, "{}", repr),
+    }
+  }
+}
+
+fn recover_repr(parsed_source: Option<&ParsedSource>, expr: &Expr) -> ExprDef {
+  ExprDef::Other {
+    repr: span_to_repr(parsed_source, &expr.span()),
+  }
+}
+
+fn object_prop_to_def(
+  parsed_source: Option<&ParsedSource>,
+  prop_or_spread: &PropOrSpread,
+) -> ObjectPropDef {
+  let prop = match prop_or_spread {
+    PropOrSpread::Spread(spread) => {
+      return ObjectPropDef::Other {
+        repr: span_to_repr(parsed_source, &spread.span()),
+      }
+    }
+    PropOrSpread::Prop(prop) => prop,
+  };
+
+  match &**prop {
+    Prop::Shorthand(ident) => ObjectPropDef::Shorthand {
+      key: ident.sym.to_string(),
+    },
+    Prop::KeyValue(key_value) => {
+      // Computed keys recover their raw text the same way
+      // `prop_name_to_string` already does for object patterns.
+      if matches!(key_value.key, PropName::Computed(_)) {
+        ObjectPropDef::Other {
+          repr: span_to_repr(parsed_source, &prop.span()),
+        }
+      } else {
+        ObjectPropDef::KeyValue {
+          key: prop_name_to_string(parsed_source, &key_value.key),
+          value: Box::new(expr_to_expr_def(parsed_source, &key_value.value)),
+        }
+      }
+    }
+    Prop::Assign(_) | Prop::Getter(_) | Prop::Setter(_) | Prop::Method(_) => {
+      ObjectPropDef::Other {
+        repr: span_to_repr(parsed_source, &prop.span()),
+      }
+    }
+  }
+}
+
+/// Converts an `Expr` into its structured `ExprDef` representation. Anything
+/// not modelled structurally falls back to `ExprDef::Other`, recovering the
+/// original source text via the `ParsedSource` when one is available.
+pub fn expr_to_expr_def(
+  parsed_source: Option<&ParsedSource>,
+  expr: &Expr,
+) -> ExprDef {
+  match expr {
+    Expr::Lit(lit) => match lit {
+      Lit::Str(str_) => ExprDef::Str {
+        value: str_.value.to_string(),
+      },
+      Lit::Num(num) => ExprDef::Num { value: num.value },
+      Lit::Bool(bool_) => ExprDef::Bool { value: bool_.value },
+      Lit::Null(_) => ExprDef::Null,
+      Lit::BigInt(bigint) => ExprDef::BigInt {
+        value: bigint.value.to_string(),
+      },
+      Lit::Regex(regex) => ExprDef::Regex {
+        pattern: regex.exp.to_string(),
+        flags: regex.flags.to_string(),
+      },
+      Lit::JSXText(_) => recover_repr(parsed_source, expr),
+    },
+    Expr::Array(array_lit) => {
+      let elements = array_lit
+        .elems
+        .iter()
+        .map(|maybe_expr_or_spread| {
+          maybe_expr_or_spread.as_ref().map(|e| {
+            // A spread element (`...xs`) changes the array's actual
+            // meaning (spread-concat vs. a literal element), so it can't
+            // be modelled by recursing into `e.expr` alone; fall back to
+            // the raw source text the same way `object_prop_to_def`
+            // handles `PropOrSpread::Spread`.
+            if e.spread.is_some() {
+              ExprDef::Other {
+                repr: span_to_repr(parsed_source, &e.span()),
+              }
+            } else {
+              expr_to_expr_def(parsed_source, &e.expr)
+            }
+          })
+        })
+        .collect();
+      ExprDef::Array { elements }
+    }
+    Expr::Object(object_lit) => {
+      let props = object_lit
+        .props
+        .iter()
+        .map(|prop| object_prop_to_def(parsed_source, prop))
+        .collect();
+      ExprDef::Object { props }
+    }
+    Expr::Ident(ident) => ExprDef::Ident {
+      name: ident.sym.to_string(),
+    },
+    _ => recover_repr(parsed_source, expr),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use deno_ast::parse_program;
+  use deno_ast::ParseParams;
+  use deno_ast::SourceTextInfo;
+
+  fn parse(source: &str) -> ParsedSource {
+    parse_program(ParseParams {
+      specifier: "file:///test.ts".to_string(),
+      text_info: SourceTextInfo::from_string(source.to_string()),
+      media_type: deno_ast::MediaType::TypeScript,
+      capture_tokens: true,
+      maybe_syntax: None,
+      scope_analysis: false,
+    })
+    .unwrap()
+  }
+
+  /// The module's sole statement must be `function f(a = <expr>) {}`.
+  fn first_default_expr(parsed_source: &ParsedSource) -> Expr {
+    use deno_ast::swc::ast::Decl;
+    use deno_ast::swc::ast::ModuleItem;
+    use deno_ast::swc::ast::Pat;
+    use deno_ast::swc::ast::Program;
+    use deno_ast::swc::ast::Stmt;
+
+    let module = match parsed_source.program_ref() {
+      Program::Module(module) => module,
+      _ => panic!("expected a module"),
+    };
+    let fn_decl = match &module.body[0] {
+      ModuleItem::Stmt(Stmt::Decl(Decl::Fn(fn_decl))) => fn_decl,
+      _ => panic!("expected a function declaration"),
+    };
+    let pat = &fn_decl.function.params[0].pat;
+    match pat {
+      Pat::Assign(assign_pat) => (*assign_pat.right).clone(),
+      _ => panic!("expected an assign pattern"),
+    }
+  }
+
+  #[test]
+  fn array_with_holes() {
+    let parsed_source = parse("function f(a = [1, , 3]) {}");
+    let expr = first_default_expr(&parsed_source);
+    let def = expr_to_expr_def(Some(&parsed_source), &expr);
+    assert_eq!(def.to_string(), "[1, , 3]");
+    match def {
+      ExprDef::Array { elements } => {
+        assert_eq!(elements.len(), 3);
+        assert!(elements[1].is_none());
+      }
+      _ => panic!("expected ExprDef::Array"),
+    }
+  }
+
+  #[test]
+  fn array_spread_falls_back_to_other() {
+    let parsed_source = parse("function f(a = [...xs, 1]) {}");
+    let expr = first_default_expr(&parsed_source);
+    let def = expr_to_expr_def(Some(&parsed_source), &expr);
+    assert_eq!(def.to_string(), "[...xs, 1]");
+    match def {
+      ExprDef::Array { elements } => {
+        assert_eq!(elements.len(), 2);
+        assert!(matches!(elements[0], Some(ExprDef::Other { .. })));
+        assert!(
+          matches!(elements[1], Some(ExprDef::Num { value }) if value == 1.0)
+        );
+      }
+      _ => panic!("expected ExprDef::Array"),
+    }
+  }
+
+  #[test]
+  fn object_literal_recurses_into_key_values() {
+    let parsed_source = parse("function f(a = { x: 1, y: \"s\" }) {}");
+    let expr = first_default_expr(&parsed_source);
+    let def = expr_to_expr_def(Some(&parsed_source), &expr);
+    assert_eq!(def.to_string(), "{x: 1, y: \"s\"}");
+  }
+
+  #[test]
+  fn object_literal_spread_falls_back_to_other() {
+    let parsed_source = parse("function f(a = { ...rest, x: 1 }) {}");
+    let expr = first_default_expr(&parsed_source);
+    let def = expr_to_expr_def(Some(&parsed_source), &expr);
+    match def {
+      ExprDef::Object { props } => {
+        assert!(matches!(props[0], ObjectPropDef::Other { .. }));
+        assert!(matches!(props[1], ObjectPropDef::KeyValue { .. }));
+      }
+      _ => panic!("expected ExprDef::Object"),
+    }
+  }
+
+  #[test]
+  fn unsupported_expr_recovers_source_text() {
+    let parsed_source = parse("function f(a = b ? 1 : 2) {}");
+    let expr = first_default_expr(&parsed_source);
+    let def = expr_to_expr_def(Some(&parsed_source), &expr);
+    assert_eq!(def.to_string(), "b ? 1 : 2");
+    assert!(matches!(def, ExprDef::Other { .. }));
+  }
+}